@@ -47,13 +47,41 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Loading a whole config struct
+//! With the `derive` feature enabled, `#[derive(FromEnv)]` generates a
+//! `from_env` constructor that loads every field and aggregates all
+//! failures into a single [`Error::Multiple`].
+//! ```ignore
+//! #[derive(strict_env::FromEnv)]
+//! #[env(prefix = "APP_")]
+//! struct Config {
+//!     // `rename` is a literal full override, so this field is read
+//!     // from `DATABASE_URL`, not `APP_DATABASE_URL`.
+//!     #[env(rename = "DATABASE_URL")]
+//!     database_url: String,
+//!     // reads `APP_PORT`, falling back to "8080" if missing or empty.
+//!     #[env(default = "8080")]
+//!     port: u16,
+//!     // reads `APP_TIMEOUT`; missing or empty yields `None`.
+//!     timeout: Option<u64>,
+//! }
+//!
+//! let config = Config::from_env()?;
+//! # Ok::<(), strict_env::Error>(())
+//! ```
 
 use std::{
     env::{self, VarError},
-    ffi::OsString,
+    ffi::{OsStr, OsString},
+    fmt::{Display, Write as _},
+    path::PathBuf,
     str::FromStr,
 };
 
+#[cfg(feature = "derive")]
+pub use strict_env_derive::FromEnv;
+
 /// Parse an environment variable into a value that implements
 /// [`FromStr`](std::str::FromStr).
 ///
@@ -138,6 +166,454 @@ where
     parse_optional(name).map(Option::unwrap_or_default)
 }
 
+/// Like [`parse_or_default`](crate::parse_or_default), but also writes
+/// `default` back into the process environment.
+///
+/// When the variable is missing or empty, this sets it (via
+/// [`env::set_var`]) to the serialized default before returning it, so
+/// that subprocesses and later reads observe a consistent value.
+///
+/// # Errors
+/// Returns an error if the requested environment variable contains invalid
+/// UTF-8 or has a value that cannot be parsed into the target type.
+pub fn parse_or_set_default<T: FromStr + Display + Default>(
+    name: &str,
+    default: T,
+) -> Result<T, Error>
+where
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    parse_optional(name)?.map_or_else(
+        || {
+            env::set_var(name, default.to_string());
+            Ok(default)
+        },
+        Ok,
+    )
+}
+
+/// Types that can be parsed directly from an [`OsStr`], without
+/// requiring valid UTF-8.
+///
+/// [`PathBuf`] and [`OsString`] implement this trait directly, since
+/// they can represent any platform-native bytes. [`String`] implements
+/// it the same way [`parse`](crate::parse) does, by requiring valid
+/// UTF-8.
+///
+/// There is no blanket implementation for
+/// [`FromStr`](std::str::FromStr) types: both [`PathBuf`] and
+/// [`OsString`] already implement `FromStr` themselves, so a blanket
+/// impl here would conflict with their dedicated, non-UTF-8-requiring
+/// implementations below. Other `FromStr` types need their own
+/// `FromOsStr` impl (typically just UTF-8-validating the value and
+/// delegating to `FromStr::from_str`, as the [`String`] impl below
+/// does) to be usable with [`parse_os`](crate::parse_os).
+pub trait FromOsStr: Sized {
+    /// Parse `value`, which is not guaranteed to be valid UTF-8.
+    ///
+    /// # Errors
+    /// Returns [`FromOsStrError`] if `value` cannot be parsed.
+    fn from_os_str(value: &OsStr) -> Result<Self, FromOsStrError>;
+}
+
+/// Error produced by a [`FromOsStr`] implementation.
+#[derive(Debug, thiserror::Error)]
+pub enum FromOsStrError {
+    /// The value was not valid UTF-8, and the target type requires it.
+    #[error("value is not valid UTF-8")]
+    InvalidUtf8,
+    /// The value was valid UTF-8, but could not be parsed.
+    #[error(transparent)]
+    Parse(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl FromOsStr for String {
+    fn from_os_str(value: &OsStr) -> Result<Self, FromOsStrError> {
+        value
+            .to_str()
+            .map(ToOwned::to_owned)
+            .ok_or(FromOsStrError::InvalidUtf8)
+    }
+}
+
+impl FromOsStr for PathBuf {
+    fn from_os_str(value: &OsStr) -> Result<Self, FromOsStrError> {
+        Ok(Self::from(value))
+    }
+}
+
+impl FromOsStr for OsString {
+    fn from_os_str(value: &OsStr) -> Result<Self, FromOsStrError> {
+        Ok(value.to_os_string())
+    }
+}
+
+/// Parse an environment variable into a value that implements
+/// [`FromOsStr`], reading its raw bytes instead of requiring valid
+/// UTF-8.
+///
+/// This is the counterpart to [`parse`](crate::parse) for types such as
+/// [`PathBuf`] that can represent arbitrary platform-native bytes.
+///
+/// # Errors
+/// Returns an error if the requested environment variable is missing or
+/// empty, or has a value that cannot be parsed into the target type.
+pub fn parse_os<T: FromOsStr>(name: &str) -> Result<T, Error> {
+    let value = match env::var_os(name) {
+        Some(value) if !value.is_empty() => value,
+        _ => {
+            return Err(Error::Missing {
+                name: name.to_owned(),
+            })
+        }
+    };
+    T::from_os_str(&value).map_err(|err| match err {
+        FromOsStrError::InvalidUtf8 => Error::InvalidUtf8 {
+            name: name.to_owned(),
+            value,
+        },
+        FromOsStrError::Parse(source) => Error::InvalidValue {
+            name: name.to_owned(),
+            value: value.to_string_lossy().into_owned(),
+            source,
+        },
+    })
+}
+
+/// Like [`parse_os`](crate::parse_os), but allows the environment
+/// variable to be missing or empty.
+///
+/// # Errors
+/// Returns an error if the requested environment variable has a value
+/// that cannot be parsed into the target type.
+pub fn parse_os_optional<T: FromOsStr>(name: &str) -> Result<Option<T>, Error> {
+    match parse_os(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::Missing { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Parse a delimited environment variable into a [`Vec`] of values that
+/// each implement [`FromStr`](std::str::FromStr), e.g. `HOSTS=a.com,b.com`
+/// with `separator` set to `","`.
+///
+/// Empty segments (such as the one between two consecutive separators)
+/// are treated as parse errors. Use [`parse_list_skip_empty`] to ignore
+/// them instead.
+///
+/// # Errors
+/// Returns an error if the requested environment variable is missing or
+/// empty, contains invalid UTF-8, or has a value where any segment
+/// (after splitting on `separator`) cannot be parsed into the target
+/// type. In the latter case, [`Error::InvalidListElement`] identifies
+/// the failing segment's index along with the full original value.
+pub fn parse_list<T: FromStr>(name: &str, separator: &str) -> Result<Vec<T>, Error>
+where
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    parse_list_impl(name, separator, false)
+}
+
+/// Like [`parse_list`](crate::parse_list), but skips empty segments
+/// instead of treating them as parse errors.
+///
+/// # Errors
+/// See [`parse_list`](crate::parse_list).
+pub fn parse_list_skip_empty<T: FromStr>(name: &str, separator: &str) -> Result<Vec<T>, Error>
+where
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    parse_list_impl(name, separator, true)
+}
+
+/// Like [`parse_list`](crate::parse_list), but allows the environment
+/// variable to be missing or empty.
+///
+/// # Errors
+/// See [`parse_list`](crate::parse_list).
+pub fn parse_list_optional<T: FromStr>(
+    name: &str,
+    separator: &str,
+) -> Result<Option<Vec<T>>, Error>
+where
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    match parse_list(name, separator) {
+        Ok(values) => Ok(Some(values)),
+        Err(Error::Missing { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+fn parse_list_impl<T: FromStr>(
+    name: &str,
+    separator: &str,
+    skip_empty: bool,
+) -> Result<Vec<T>, Error>
+where
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let full_value = parse::<String>(name)?;
+    let mut values = Vec::new();
+    for (index, segment) in full_value.split(separator).enumerate() {
+        if segment.is_empty() {
+            if skip_empty {
+                continue;
+            }
+            return Err(Error::InvalidListElement {
+                name: name.to_owned(),
+                index,
+                full_value,
+                source: EmptyListElement.into(),
+            });
+        }
+        let parsed = T::from_str(segment).map_err(|source| Error::InvalidListElement {
+            name: name.to_owned(),
+            index,
+            full_value: full_value.clone(),
+            source: source.into(),
+        })?;
+        values.push(parsed);
+    }
+    Ok(values)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("list element is empty")]
+struct EmptyListElement;
+
+/// Builds a single value by concatenating literal strings and
+/// environment variable lookups, then parsing the result.
+///
+/// This is useful when one logical setting is split across several
+/// environment variables, e.g. assembling a socket address from
+/// `ADDR` and `PORT`.
+///
+/// # Example
+/// ```
+/// # fn main() -> Result<(), strict_env::Error> {
+/// std::env::set_var("ADDR", "127.0.0.1");
+/// std::env::remove_var("PORT");
+/// let addr: std::net::SocketAddr = strict_env::Composed::new()
+///     .var("ADDR")
+///     .literal(":")
+///     .var_or("PORT", "8000")
+///     .parse()?;
+/// assert_eq!(addr.port(), 8000);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Composed {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Literal(String),
+    Var {
+        name: String,
+        default: Option<String>,
+    },
+}
+
+impl Composed {
+    /// Start building a new composed value.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a literal string segment.
+    #[must_use]
+    pub fn literal(mut self, value: impl Into<String>) -> Self {
+        self.segments.push(Segment::Literal(value.into()));
+        self
+    }
+
+    /// Append a segment resolved from an environment variable.
+    ///
+    /// Missing or invalid UTF-8 values are reported the same way as
+    /// [`parse`](crate::parse). Use [`var_or`](Self::var_or) to supply a
+    /// fallback instead.
+    #[must_use]
+    pub fn var(self, name: impl Into<String>) -> Self {
+        self.push_var(name, None)
+    }
+
+    /// Like [`var`](Self::var), but falls back to `default` when the
+    /// variable is missing or empty instead of failing.
+    #[must_use]
+    pub fn var_or(self, name: impl Into<String>, default: impl Into<String>) -> Self {
+        self.push_var(name, Some(default.into()))
+    }
+
+    fn push_var(mut self, name: impl Into<String>, default: Option<String>) -> Self {
+        self.segments.push(Segment::Var {
+            name: name.into(),
+            default,
+        });
+        self
+    }
+
+    /// Resolve every segment, concatenate them in order, then parse the
+    /// combined string into `T`.
+    ///
+    /// # Errors
+    /// Returns an error if a referenced environment variable is missing
+    /// or empty and has no default, contains invalid UTF-8, or if the
+    /// concatenated value cannot be parsed into `T`.
+    pub fn parse<T: FromStr>(&self) -> Result<T, Error>
+    where
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let mut combined = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(value) => combined.push_str(value),
+                Segment::Var { name, default } => {
+                    let resolved = match parse::<String>(name) {
+                        Ok(value) => value,
+                        Err(Error::Missing { .. }) => default.clone().ok_or_else(|| Error::Missing {
+                            name: name.clone(),
+                        })?,
+                        Err(err) => return Err(err),
+                    };
+                    combined.push_str(&resolved);
+                }
+            }
+        }
+        T::from_str(&combined).map_err(|source| Error::InvalidComposedValue {
+            description: self.describe(),
+            value: combined,
+            source: source.into(),
+        })
+    }
+
+    fn describe(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(value) => format!("{value:?}"),
+                Segment::Var { name, .. } => name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+/// Accumulates several [`parse`](crate::parse)/[`parse_optional`](crate::parse_optional) calls.
+///
+/// Every missing or invalid variable is reported together via
+/// [`Error::Multiple`] instead of failing on the first one. Each
+/// registration method returns a [`Handle`] immediately; the handle is
+/// populated by [`finish`](Self::finish) if its own variable resolves
+/// successfully, even when other registered variables fail.
+///
+/// # Example
+/// ```
+/// # fn main() -> Result<(), strict_env::Error> {
+/// std::env::set_var("HOST", "example.com");
+/// std::env::set_var("PORT", "9001");
+/// let mut collector = strict_env::Collector::new();
+/// let host = collector.parse::<String>("HOST");
+/// let port = collector.parse::<u16>("PORT");
+/// collector.finish()?;
+/// assert_eq!(host.into_inner(), "example.com");
+/// assert_eq!(port.into_inner(), 9001);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Collector {
+    tasks: Vec<CollectorTask>,
+}
+
+type CollectorTask = Box<dyn FnOnce(&mut Vec<Error>)>;
+
+/// A deferred slot for a value registered with a [`Collector`].
+///
+/// Populated by [`Collector::finish`] when this value resolves
+/// successfully.
+pub struct Handle<T>(std::rc::Rc<std::cell::RefCell<Option<T>>>);
+
+impl<T> Handle<T> {
+    /// Returns the resolved value.
+    ///
+    /// # Panics
+    /// Panics if [`Collector::finish`] has not yet been called, or if
+    /// this handle's variable was missing or invalid.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0.borrow_mut().take().expect(
+            "Handle read before Collector::finish succeeded",
+        )
+    }
+}
+
+impl Collector {
+    /// Create an empty collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a required environment variable.
+    ///
+    /// Returns a [`Handle`] that will hold the parsed value once
+    /// [`finish`](Self::finish) succeeds.
+    pub fn parse<T>(&mut self, name: &str) -> Handle<T>
+    where
+        T: FromStr + 'static,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let name = name.to_owned();
+        self.register(move || crate::parse::<T>(&name))
+    }
+
+    /// Register an optional environment variable.
+    ///
+    /// Returns a [`Handle`] that will hold the parsed value once
+    /// [`finish`](Self::finish) succeeds.
+    pub fn parse_optional<T>(&mut self, name: &str) -> Handle<Option<T>>
+    where
+        T: FromStr + 'static,
+        T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let name = name.to_owned();
+        self.register(move || crate::parse_optional::<T>(&name))
+    }
+
+    fn register<T: 'static>(&mut self, loader: impl FnOnce() -> Result<T, Error> + 'static) -> Handle<T> {
+        let slot = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let slot_for_task = std::rc::Rc::clone(&slot);
+        self.tasks.push(Box::new(move |errors| match loader() {
+            Ok(value) => *slot_for_task.borrow_mut() = Some(value),
+            Err(err) => errors.push(err),
+        }));
+        Handle(slot)
+    }
+
+    /// Resolve every registered variable, filling in the [`Handle`]
+    /// returned by each registration.
+    ///
+    /// # Errors
+    /// Returns [`Error::Multiple`] containing every variable that was
+    /// missing or invalid.
+    pub fn finish(self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+        for task in self.tasks {
+            task(&mut errors);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Multiple(errors))
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 /// Error type for this library.
 pub enum Error {
@@ -168,6 +644,50 @@ pub enum Error {
         /// The underlying error that occurred during parsing.
         source: Box<dyn std::error::Error + Send + Sync>,
     },
+    #[error("Error parsing environment variable {name:?} at list index {index}: {source} (full value: {full_value:?})")]
+    /// The environment variable exists and is valid UTF-8, but a
+    /// segment of its list value (after splitting on the separator)
+    /// could not be parsed into the target type.
+    InvalidListElement {
+        /// Name of the requested environment variable.
+        name: String,
+        /// Index of the list segment that failed to parse.
+        index: usize,
+        /// Full, unsplit value of the environment variable.
+        full_value: String,
+        #[source]
+        /// The underlying error that occurred during parsing.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Error parsing composed value ({description}): {source} (full value: {value:?})")]
+    /// Every segment of a [`Composed`] value resolved successfully, but
+    /// the concatenated result could not be parsed into the target
+    /// type.
+    InvalidComposedValue {
+        /// Description of the segments that were concatenated, e.g.
+        /// `ADDR + ":" + PORT`.
+        description: String,
+        /// The concatenated value that failed to parse.
+        value: String,
+        #[source]
+        /// The underlying error that occurred during parsing.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// Multiple environment variables were missing or invalid.
+    ///
+    /// This is returned by APIs that load several variables at once
+    /// (such as `#[derive(FromEnv)]` and [`Collector`]) so that every
+    /// failure can be reported together instead of one at a time.
+    #[error("{}", display_multiple(.0))]
+    Multiple(Vec<Self>),
+}
+
+fn display_multiple(errors: &[Error]) -> String {
+    let mut message = format!("{} environment variable error(s):", errors.len());
+    for error in errors {
+        let _ = write!(message, "\n  - {error}");
+    }
+    message
 }
 
 #[allow(
@@ -319,6 +839,328 @@ mod tests {
         }
     }
 
+    mod parse_or_set_default {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard = EnvGuard::with("TEST_VAR", "255");
+            let value: u8 = parse_or_set_default("TEST_VAR", 0).unwrap();
+            assert_eq!(value, 255);
+            assert_eq!(env::var("TEST_VAR").unwrap(), "255");
+        }
+
+        #[test]
+        #[serial]
+        fn missing_sets_default_in_environment() {
+            let _guard = EnvGuard::without("TEST_VAR");
+            let value: u8 = parse_or_set_default("TEST_VAR", 42).unwrap();
+            assert_eq!(value, 42);
+            assert_eq!(env::var("TEST_VAR").unwrap(), "42");
+        }
+
+        #[test]
+        #[serial]
+        fn empty_sets_default_in_environment() {
+            let _guard = EnvGuard::with("TEST_VAR", "");
+            let value: u8 = parse_or_set_default("TEST_VAR", 42).unwrap();
+            assert_eq!(value, 42);
+            assert_eq!(env::var("TEST_VAR").unwrap(), "42");
+        }
+
+        #[test]
+        #[serial]
+        fn invalid_value() {
+            let _guard = EnvGuard::with("TEST_VAR", "256");
+            let error = parse_or_set_default::<u8>("TEST_VAR", 0).unwrap_err();
+            assert!(matches!(error, Error::InvalidValue { .. }));
+        }
+    }
+
+    mod parse_list {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard = EnvGuard::with("TEST_VAR", "1,2,3");
+            let value: Vec<u8> = parse_list("TEST_VAR", ",").unwrap();
+            assert_eq!(value, vec![1, 2, 3]);
+        }
+
+        #[test]
+        #[serial]
+        fn missing() {
+            let _guard = EnvGuard::without("TEST_VAR");
+            let error = parse_list::<u8>("TEST_VAR", ",").unwrap_err();
+            assert!(matches!(error, Error::Missing { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn empty() {
+            let _guard = EnvGuard::with("TEST_VAR", "");
+            let error = parse_list::<u8>("TEST_VAR", ",").unwrap_err();
+            assert!(matches!(error, Error::Missing { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn empty_element_errors() {
+            let _guard = EnvGuard::with("TEST_VAR", "1,,3");
+            let error = parse_list::<u8>("TEST_VAR", ",").unwrap_err();
+            assert!(matches!(error, Error::InvalidListElement { index: 1, .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn invalid_value() {
+            let _guard = EnvGuard::with("TEST_VAR", "1,x,3");
+            let error = parse_list::<u8>("TEST_VAR", ",").unwrap_err();
+            assert!(matches!(error, Error::InvalidListElement { index: 1, .. }));
+        }
+    }
+
+    mod parse_list_skip_empty {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn skips_empty_elements() {
+            let _guard = EnvGuard::with("TEST_VAR", "1,,3");
+            let value: Vec<u8> = parse_list_skip_empty("TEST_VAR", ",").unwrap();
+            assert_eq!(value, vec![1, 3]);
+        }
+    }
+
+    mod parse_list_optional {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard = EnvGuard::with("TEST_VAR", "1,2,3");
+            let value = parse_list_optional::<u8>("TEST_VAR", ",").unwrap();
+            assert_eq!(value, Some(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[serial]
+        fn missing() {
+            let _guard = EnvGuard::without("TEST_VAR");
+            let value = parse_list_optional::<u8>("TEST_VAR", ",").unwrap();
+            assert_eq!(value, None);
+        }
+    }
+
+    mod composed {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard1 = EnvGuard::with("ADDR", "127.0.0.1");
+            let _guard2 = EnvGuard::with("PORT", "9001");
+            let addr: std::net::SocketAddr = Composed::new()
+                .var("ADDR")
+                .literal(":")
+                .var("PORT")
+                .parse()
+                .unwrap();
+            assert_eq!(addr.to_string(), "127.0.0.1:9001");
+        }
+
+        #[test]
+        #[serial]
+        fn var_or_uses_default_when_missing() {
+            let _guard1 = EnvGuard::with("ADDR", "127.0.0.1");
+            let _guard2 = EnvGuard::without("PORT");
+            let addr: std::net::SocketAddr = Composed::new()
+                .var("ADDR")
+                .literal(":")
+                .var_or("PORT", "8000")
+                .parse()
+                .unwrap();
+            assert_eq!(addr.port(), 8000);
+        }
+
+        #[test]
+        #[serial]
+        fn missing_var_without_default() {
+            let _guard = EnvGuard::without("ADDR");
+            let error = Composed::new()
+                .var("ADDR")
+                .parse::<String>()
+                .unwrap_err();
+            assert!(matches!(error, Error::Missing { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn invalid_combined_value() {
+            let _guard1 = EnvGuard::with("ADDR", "not-an-ip");
+            let _guard2 = EnvGuard::with("PORT", "9001");
+            let error = Composed::new()
+                .var("ADDR")
+                .literal(":")
+                .var("PORT")
+                .parse::<std::net::SocketAddr>()
+                .unwrap_err();
+            assert!(matches!(error, Error::InvalidComposedValue { .. }));
+            assert_eq!(
+                error.to_string(),
+                "Error parsing composed value (ADDR + \":\" + PORT): invalid socket address syntax (full value: \"not-an-ip:9001\")"
+            );
+        }
+    }
+
+    mod parse_os {
+        use super::*;
+        use std::path::PathBuf;
+
+        /// A `FromOsStr` type whose parsing can fail, used to exercise
+        /// [`Error::InvalidValue`] via [`FromOsStrError::Parse`].
+        #[derive(Debug)]
+        struct UppercaseAscii(String);
+
+        impl FromOsStr for UppercaseAscii {
+            fn from_os_str(value: &OsStr) -> Result<Self, FromOsStrError> {
+                let value = value.to_str().ok_or(FromOsStrError::InvalidUtf8)?;
+                if value.is_ascii() {
+                    Ok(Self(value.to_uppercase()))
+                } else {
+                    Err(FromOsStrError::Parse("value is not ASCII".into()))
+                }
+            }
+        }
+
+        #[test]
+        #[serial]
+        fn valid_path() {
+            let _guard = EnvGuard::with("TEST_VAR", "/tmp/file.txt");
+            let value: PathBuf = parse_os("TEST_VAR").unwrap();
+            assert_eq!(value, PathBuf::from("/tmp/file.txt"));
+        }
+
+        #[test]
+        #[serial]
+        fn valid_string() {
+            let _guard = EnvGuard::with("TEST_VAR", "hello");
+            let value: String = parse_os("TEST_VAR").unwrap();
+            assert_eq!(value, "hello");
+        }
+
+        #[test]
+        #[serial]
+        fn missing() {
+            let _guard = EnvGuard::without("TEST_VAR");
+            let error = parse_os::<PathBuf>("TEST_VAR").unwrap_err();
+            assert!(matches!(error, Error::Missing { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn non_utf8_path_is_allowed() {
+            let invalid_unicode_bytes = [b'f', b'o', b'o', 0x80];
+            let invalid_unicode = OsStr::from_raw_bytes(&invalid_unicode_bytes[..]).unwrap();
+            let _guard = EnvGuard::with("TEST_VAR", &invalid_unicode);
+            let value: PathBuf = parse_os("TEST_VAR").unwrap();
+            assert_eq!(value.as_os_str(), invalid_unicode);
+        }
+
+        #[test]
+        #[serial]
+        fn non_utf8_rejected_for_string() {
+            let invalid_unicode_bytes = [b'f', b'o', b'o', 0x80];
+            let invalid_unicode = OsStr::from_raw_bytes(&invalid_unicode_bytes[..]).unwrap();
+            let _guard = EnvGuard::with("TEST_VAR", &invalid_unicode);
+            let error = parse_os::<String>("TEST_VAR").unwrap_err();
+            assert!(matches!(error, Error::InvalidUtf8 { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn invalid_value() {
+            let _guard = EnvGuard::with("TEST_VAR", "café");
+            let error = parse_os::<UppercaseAscii>("TEST_VAR").unwrap_err();
+            assert!(matches!(error, Error::InvalidValue { .. }));
+        }
+
+        #[test]
+        #[serial]
+        fn custom_from_os_str_impl() {
+            let _guard = EnvGuard::with("TEST_VAR", "hello");
+            let value: UppercaseAscii = parse_os("TEST_VAR").unwrap();
+            assert_eq!(value.0, "HELLO");
+        }
+    }
+
+    mod parse_os_optional {
+        use super::*;
+        use std::path::PathBuf;
+
+        #[test]
+        #[serial]
+        fn missing() {
+            let _guard = EnvGuard::without("TEST_VAR");
+            let value = parse_os_optional::<PathBuf>("TEST_VAR").unwrap();
+            assert_eq!(value, None);
+        }
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard = EnvGuard::with("TEST_VAR", "/tmp/file.txt");
+            let value = parse_os_optional::<PathBuf>("TEST_VAR").unwrap();
+            assert_eq!(value, Some(PathBuf::from("/tmp/file.txt")));
+        }
+    }
+
+    mod collector {
+        use super::*;
+
+        #[test]
+        #[serial]
+        fn valid() {
+            let _guard1 = EnvGuard::with("HOST", "example.com");
+            let _guard2 = EnvGuard::with("PORT", "9001");
+            let mut collector = Collector::new();
+            let host = collector.parse::<String>("HOST");
+            let port = collector.parse::<u16>("PORT");
+            collector.finish().unwrap();
+            assert_eq!(host.into_inner(), "example.com");
+            assert_eq!(port.into_inner(), 9001);
+        }
+
+        #[test]
+        #[serial]
+        fn parse_optional_handle() {
+            let _guard = EnvGuard::without("MISSING_VAR");
+            let mut collector = Collector::new();
+            let value = collector.parse_optional::<u8>("MISSING_VAR");
+            collector.finish().unwrap();
+            assert_eq!(value.into_inner(), None);
+        }
+
+        #[test]
+        #[serial]
+        fn aggregates_every_failure() {
+            let _guard1 = EnvGuard::without("MISSING_VAR");
+            let _guard2 = EnvGuard::with("INVALID_VAR", "not-a-number");
+            let mut collector = Collector::new();
+            let _missing = collector.parse::<String>("MISSING_VAR");
+            let _invalid = collector.parse::<u8>("INVALID_VAR");
+            let error = collector.finish().unwrap_err();
+            let Error::Multiple(errors) = error else {
+                panic!("expected Error::Multiple");
+            };
+            assert_eq!(errors.len(), 2);
+            assert!(matches!(errors[0], Error::Missing { .. }));
+            assert!(matches!(errors[1], Error::InvalidValue { .. }));
+        }
+    }
+
     mod error {
         use super::*;
 
@@ -356,6 +1198,35 @@ mod tests {
             );
         }
         #[test]
+        fn invalid_list_element() {
+            let source = "".parse::<u8>().unwrap_err();
+            let error = Error::InvalidListElement {
+                name: "TEST_VAR".into(),
+                index: 1,
+                full_value: "1,,3".into(),
+                source: source.into(),
+            };
+            assert_eq!(
+                error.to_string(),
+                "Error parsing environment variable \"TEST_VAR\" at list index 1: cannot parse integer from empty string (full value: \"1,,3\")",
+            );
+        }
+        #[test]
+        fn multiple() {
+            let error = Error::Multiple(vec![
+                Error::Missing {
+                    name: "FIRST".into(),
+                },
+                Error::Missing {
+                    name: "SECOND".into(),
+                },
+            ]);
+            assert_eq!(
+                error.to_string(),
+                "2 environment variable error(s):\n  - Missing or empty environment variable \"FIRST\"\n  - Missing or empty environment variable \"SECOND\"",
+            );
+        }
+        #[test]
         fn invalid_value() {
             let source = "".parse::<u8>().unwrap_err();
             let error = Error::InvalidValue {
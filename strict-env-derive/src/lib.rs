@@ -0,0 +1,345 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
+
+//! Proc-macro companion crate for `strict_env`.
+//!
+//! This crate is not meant to be used directly; enable the `derive`
+//! feature of `strict_env` instead, which re-exports
+//! [`macro@FromEnv`] from here.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Fields, LitStr, Meta, MetaNameValue, NestedMeta,
+};
+
+/// Derive whole-struct environment loading for a config type.
+///
+/// Generates `<Type>::from_env() -> Result<Self, strict_env::Error>`,
+/// which loads every field from the environment using [`strict_env::parse`],
+/// [`strict_env::parse_optional`], or a field-level default, then returns
+/// a single [`strict_env::Error::Multiple`] listing every missing or
+/// invalid variable instead of failing on the first one.
+///
+/// # Field attributes
+/// - `#[env(rename = "DATABASE_URL")]` overrides the variable name for a
+///   field (by default, the field name in `SCREAMING_SNAKE_CASE`) with a
+///   literal full name, bypassing any container-level `prefix`.
+/// - `#[env(prefix = "APP_")]` on the struct prepends a prefix to every
+///   field's variable name (except those with `rename`).
+/// - `#[env(default = "8080")]` supplies a literal fallback, parsed with
+///   the field's [`FromStr`](std::str::FromStr) impl, for when the
+///   variable is missing or empty. Cannot be combined with an
+///   `Option<T>` field, which already defaults to `None`.
+///
+/// `Option<T>` fields are loaded with [`strict_env::parse_optional`] and
+/// never cause the aggregated error to contain a "missing" entry for
+/// that field.
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn derive_from_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let struct_name = &input.ident;
+    let prefix = container_prefix(input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "FromEnv can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "FromEnv requires named fields",
+        ));
+    };
+
+    let mut field_idents = Vec::new();
+    let mut field_loaders = Vec::new();
+    let mut error_pushes = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named field");
+        let attrs = FieldAttrs::parse(field)?;
+        let ty = &field.ty;
+        let is_option = is_option(ty);
+
+        if let Some(default) = &attrs.default {
+            if is_option {
+                return Err(syn::Error::new_spanned(
+                    default,
+                    "`#[env(default = ...)]` cannot be combined with an `Option<T>` field; \
+                     `Option` fields already default to `None` when missing",
+                ));
+            }
+        }
+
+        let var_name = attrs.rename.clone().unwrap_or_else(|| {
+            format!("{prefix}{}", screaming_snake_case(&ident.to_string()))
+        });
+        let result_ident = quote::format_ident!("__{ident}_result");
+
+        let loader = match (&attrs.default, is_option) {
+            (Some(default), _) => quote! {
+                let #result_ident: ::std::result::Result<#ty, ::strict_env::Error> =
+                    match ::strict_env::parse_optional::<#ty>(#var_name) {
+                        ::std::result::Result::Ok(::std::option::Option::Some(value)) => ::std::result::Result::Ok(value),
+                        ::std::result::Result::Ok(::std::option::Option::None) => {
+                            <#ty as ::std::str::FromStr>::from_str(#default)
+                                .map_err(|source| ::strict_env::Error::InvalidValue {
+                                    name: #var_name.to_owned(),
+                                    value: #default.to_owned(),
+                                    source: ::std::convert::Into::into(source),
+                                })
+                        }
+                        ::std::result::Result::Err(err) => ::std::result::Result::Err(err),
+                    };
+            },
+            (None, true) => quote! {
+                let #result_ident = ::strict_env::parse_optional(#var_name);
+            },
+            (None, false) => quote! {
+                let #result_ident = ::strict_env::parse::<#ty>(#var_name);
+            },
+        };
+
+        field_loaders.push(loader);
+        error_pushes.push(quote! {
+            let #ident = match #result_ident {
+                ::std::result::Result::Ok(value) => ::std::option::Option::Some(value),
+                ::std::result::Result::Err(err) => {
+                    errors.push(err);
+                    ::std::option::Option::None
+                }
+            };
+        });
+        field_idents.push(ident.clone());
+    }
+
+    Ok(quote! {
+        impl #struct_name {
+            /// Load every field of this config from the environment,
+            /// returning an aggregated [`strict_env::Error::Multiple`]
+            /// if any field is missing or invalid.
+            ///
+            /// # Errors
+            /// Returns [`strict_env::Error::Multiple`] if one or more
+            /// fields could not be loaded.
+            pub fn from_env() -> ::std::result::Result<Self, ::strict_env::Error> {
+                #(#field_loaders)*
+                let mut errors = ::std::vec::Vec::new();
+                #(#error_pushes)*
+                if !errors.is_empty() {
+                    return ::std::result::Result::Err(::strict_env::Error::Multiple(errors));
+                }
+                ::std::result::Result::Ok(Self {
+                    #(#field_idents: #field_idents.expect("checked above")),*
+                })
+            }
+        }
+    })
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(path) = ty {
+        path.path.segments.last().is_some_and(|seg| seg.ident == "Option")
+    } else {
+        false
+    }
+}
+
+fn screaming_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for ch in name.chars() {
+        if ch == '_' {
+            out.push('_');
+        } else {
+            out.extend(ch.to_uppercase());
+        }
+    }
+    out
+}
+
+fn container_prefix(input: &DeriveInput) -> syn::Result<String> {
+    for attr in &input.attrs {
+        if !attr.path.is_ident("env") {
+            continue;
+        }
+        if let Meta::List(list) = attr.parse_meta()? {
+            let Some(nested) = list.nested.first() else {
+                continue;
+            };
+            let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = nested else {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "unrecognized `#[env(...)]` attribute on struct; expected `prefix = \"...\"`",
+                ));
+            };
+            if !path.is_ident("prefix") {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "unrecognized `#[env(...)]` attribute on struct; expected `prefix = \"...\"`",
+                ));
+            }
+            let syn::Lit::Str(value) = lit else {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "`#[env(prefix = ...)]` value must be a string literal",
+                ));
+            };
+            return Ok(value.value());
+        }
+    }
+    Ok(String::new())
+}
+
+struct FieldAttrs {
+    rename: Option<String>,
+    default: Option<LitStr>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut rename = None;
+        let mut default = None;
+        for attr in &field.attrs {
+            if !attr.path.is_ident("env") {
+                continue;
+            }
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in &list.nested {
+                    let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) =
+                        nested
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            nested,
+                            "unrecognized `#[env(...)]` attribute on field; expected \
+                             `rename = \"...\"` or `default = \"...\"`",
+                        ));
+                    };
+                    if path.is_ident("rename") {
+                        let syn::Lit::Str(value) = lit else {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "`#[env(rename = ...)]` value must be a string literal",
+                            ));
+                        };
+                        rename = Some(value.value());
+                    } else if path.is_ident("default") {
+                        let syn::Lit::Str(value) = lit else {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "`#[env(default = ...)]` value must be a string literal",
+                            ));
+                        };
+                        default = Some(value.clone());
+                    } else {
+                        return Err(syn::Error::new_spanned(
+                            path,
+                            "unrecognized `#[env(...)]` attribute on field; expected \
+                             `rename` or `default`",
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(Self { rename, default })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: &DeriveInput) -> syn::Result<String> {
+        expand(input).map(|tokens| tokens.to_string())
+    }
+
+    mod rename_and_prefix {
+        use super::*;
+
+        #[test]
+        fn rename_bypasses_prefix() {
+            let input: DeriveInput = syn::parse_quote! {
+                #[env(prefix = "APP_")]
+                struct Config {
+                    #[env(rename = "DATABASE_URL")]
+                    database_url: String,
+                }
+            };
+            let output = expand_str(&input).unwrap();
+            assert!(output.contains("\"DATABASE_URL\""));
+            assert!(!output.contains("\"APP_DATABASE_URL\""));
+        }
+
+        #[test]
+        fn prefix_applies_without_rename() {
+            let input: DeriveInput = syn::parse_quote! {
+                #[env(prefix = "APP_")]
+                struct Config {
+                    port: u16,
+                }
+            };
+            let output = expand_str(&input).unwrap();
+            assert!(output.contains("\"APP_PORT\""));
+        }
+    }
+
+    mod default_attr {
+        use super::*;
+
+        #[test]
+        fn rejected_on_option_field() {
+            let input: DeriveInput = syn::parse_quote! {
+                struct Config {
+                    #[env(default = "30")]
+                    timeout: Option<u64>,
+                }
+            };
+            assert!(expand(&input).is_err());
+        }
+
+        #[test]
+        fn accepted_on_plain_field() {
+            let input: DeriveInput = syn::parse_quote! {
+                struct Config {
+                    #[env(default = "8080")]
+                    port: u16,
+                }
+            };
+            assert!(expand(&input).is_ok());
+        }
+    }
+
+    mod unknown_attr {
+        use super::*;
+
+        #[test]
+        fn unknown_container_key_rejected() {
+            let input: DeriveInput = syn::parse_quote! {
+                #[env(prefx = "APP_")]
+                struct Config {
+                    port: u16,
+                }
+            };
+            assert!(expand(&input).is_err());
+        }
+
+        #[test]
+        fn unknown_field_key_rejected() {
+            let input: DeriveInput = syn::parse_quote! {
+                struct Config {
+                    #[env(renam = "PORT")]
+                    port: u16,
+                }
+            };
+            assert!(expand(&input).is_err());
+        }
+    }
+}
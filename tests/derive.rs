@@ -0,0 +1,77 @@
+#![cfg(feature = "derive")]
+
+use serial_test::serial;
+use std::env;
+use strict_env::{Error, FromEnv};
+
+#[derive(FromEnv, Debug)]
+#[env(prefix = "APP_")]
+struct Config {
+    #[env(rename = "DATABASE_URL")]
+    database_url: String,
+    #[env(default = "8080")]
+    port: u16,
+    timeout: Option<u64>,
+}
+
+struct EnvGuard {
+    name: &'static str,
+}
+
+impl EnvGuard {
+    fn with(name: &'static str, value: &str) -> Self {
+        env::set_var(name, value);
+        Self { name }
+    }
+
+    fn without(name: &'static str) -> Self {
+        env::remove_var(name);
+        Self { name }
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        env::remove_var(self.name);
+    }
+}
+
+#[test]
+#[serial]
+fn loads_every_field() {
+    let _db = EnvGuard::with("DATABASE_URL", "postgres://localhost/app");
+    let _port = EnvGuard::with("APP_PORT", "3000");
+    let _timeout = EnvGuard::with("APP_TIMEOUT", "30");
+
+    let config = Config::from_env().unwrap();
+    assert_eq!(config.database_url, "postgres://localhost/app");
+    assert_eq!(config.port, 3000);
+    assert_eq!(config.timeout, Some(30));
+}
+
+#[test]
+#[serial]
+fn falls_back_to_default_and_none() {
+    let _db = EnvGuard::with("DATABASE_URL", "postgres://localhost/app");
+    let _port = EnvGuard::without("APP_PORT");
+    let _timeout = EnvGuard::without("APP_TIMEOUT");
+
+    let config = Config::from_env().unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.timeout, None);
+}
+
+#[test]
+#[serial]
+fn aggregates_missing_required_field() {
+    let _db = EnvGuard::without("DATABASE_URL");
+    let _port = EnvGuard::without("APP_PORT");
+    let _timeout = EnvGuard::without("APP_TIMEOUT");
+
+    let error = Config::from_env().unwrap_err();
+    let Error::Multiple(errors) = error else {
+        panic!("expected Error::Multiple, got {error:?}");
+    };
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], Error::Missing { ref name } if name == "DATABASE_URL"));
+}